@@ -0,0 +1,95 @@
+//! Error types for [`crate::RotatingFileHandler`].
+//!
+//! A bare `io::Error` doesn't say which file or which step of a rename chain (e.g.
+//! `log.txt.1 -> log.txt.2`) failed. [`RotatingError`] wraps the underlying `io::Error` together
+//! with the path and operation involved, and [`IoResultExt`] lets internal calls attach that
+//! context inline with `.with_context(...)`.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The path and operation description to attach to an I/O error.
+pub struct IoErrorContext {
+    operation: String,
+    path: PathBuf,
+}
+
+impl IoErrorContext {
+    /// Describes `operation` (e.g. "rename backup") as acting on `path`.
+    pub fn new(operation: impl Into<String>, path: impl AsRef<Path>) -> Self {
+        Self {
+            operation: operation.into(),
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+/// An I/O error annotated with the path and operation that failed.
+#[derive(Debug)]
+pub struct RotatingError {
+    operation: String,
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl RotatingError {
+    /// Builds a `RotatingError` directly from its parts, for call sites that construct the
+    /// underlying `io::Error` themselves rather than propagating one via [`IoResultExt`].
+    pub fn new(operation: impl Into<String>, path: impl AsRef<Path>, source: io::Error) -> Self {
+        Self {
+            operation: operation.into(),
+            path: path.as_ref().to_path_buf(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for RotatingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} failed for {}: {}",
+            self.operation,
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for RotatingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<RotatingError> for io::Error {
+    fn from(err: RotatingError) -> Self {
+        io::Error::new(err.source.kind(), err)
+    }
+}
+
+/// Extension trait for attaching path/operation context to a fallible I/O call.
+pub trait IoResultExt<T> {
+    /// Attaches context, built lazily by `context`, to this result's error, if any.
+    fn with_context<F>(self, context: F) -> Result<T, RotatingError>
+    where
+        F: FnOnce() -> IoErrorContext;
+}
+
+impl<T> IoResultExt<T> for io::Result<T> {
+    fn with_context<F>(self, context: F) -> Result<T, RotatingError>
+    where
+        F: FnOnce() -> IoErrorContext,
+    {
+        self.map_err(|source| {
+            let IoErrorContext { operation, path } = context();
+            RotatingError {
+                operation,
+                path,
+                source,
+            }
+        })
+    }
+}
@@ -18,7 +18,7 @@
 //! use std::fs;
 //!
 //! fn main() -> std::io::Result<()> {
-//!     let mut handler = RotatingFileHandler::new("docs_log.txt", 1024, 3, None)?;
+//!     let mut handler = RotatingFileHandler::new("docs_log.txt", Some(1024), 3, None)?;
 //!     handler.emit(b"Hello, world!")?;
 //!     handler.emit(b"Logging some more data...")?;
 //!     fs::remove_file("docs_log.txt");
@@ -26,87 +26,163 @@
 //! }
 //! ```
 
-use std::fs::{File, OpenOptions};
+mod error;
+mod vfs;
+
 use std::io::{self, Write};
 use std::option::Option;
-use std::path::Path;
+
+pub use error::{IoErrorContext, IoResultExt, RotatingError};
+pub use vfs::{DiskVfs, MemoryVfs, Vfs};
 
 /// A handler for rotating log files.
 ///
 /// This struct manages a log file that rotates when it reaches a specified size.
-/// It keeps a specified number of backup files.
+/// It keeps a specified number of backup files. All I/O is performed through a [`Vfs`]
+/// backend, which defaults to [`DiskVfs`] (the real filesystem) when constructed via [`new`],
+/// but can be swapped for an in-memory or sandboxed backend via [`with_vfs`].
+///
+/// Rotation is optional: a `max_bytes` of `None` disables it entirely, and the handler simply
+/// appends forever.
+///
+/// [`new`]: RotatingFileHandler::new
+/// [`with_vfs`]: RotatingFileHandler::with_vfs
 pub struct RotatingFileHandler {
     base_path: String,
-    max_bytes: u64,
+    max_bytes: Option<u64>,
     backup_count: usize,
     current_size: u64,
-    file: File,
+    file: Box<dyn Write + Send>,
     header: Option<Vec<u8>>,
+    vfs: Box<dyn Vfs>,
 }
 
 impl RotatingFileHandler {
-    /// Creates a new `RotatingFileHandler`.
+    /// Creates a new `RotatingFileHandler` backed by the real filesystem.
     ///
     /// # Arguments
     ///
     /// * `base_path` - The base path of the log file.
-    /// * `max_bytes` - The maximum size of the log file in bytes before it rotates.
+    /// * `max_bytes` - The maximum size of the log file in bytes before it rotates, or `None` to
+    ///   disable rotation entirely.
     /// * `backup_count` - The number of backup files to keep.
     /// * `header` - An optional header to write to the log file.
     ///
     /// # Returns
     ///
-    /// An `io::Result` containing the new `RotatingFileHandler` or an error.
+    /// A `RotatingFileHandler`, or a [`RotatingError`] naming the path and step that failed.
     pub fn new(
         base_path: &str,
-        max_bytes: u64,
+        max_bytes: Option<u64>,
+        backup_count: usize,
+        header: Option<Vec<u8>>,
+    ) -> Result<Self, RotatingError> {
+        Self::with_vfs(DiskVfs, base_path, max_bytes, backup_count, header)
+    }
+
+    /// Creates a new `RotatingFileHandler` that performs all I/O through `vfs`.
+    ///
+    /// If the file already exists and is already larger than `max_bytes`, it is rotated once
+    /// before this call returns, so the handler never appends to an already-oversized file.
+    ///
+    /// # Arguments
+    ///
+    /// * `vfs` - The [`Vfs`] backend to use for all file operations.
+    /// * `base_path` - The base path of the log file, resolved by `vfs`.
+    /// * `max_bytes` - The maximum size of the log file in bytes before it rotates, or `None` to
+    ///   disable rotation entirely.
+    /// * `backup_count` - The number of backup files to keep.
+    /// * `header` - An optional header to write to the log file.
+    ///
+    /// # Returns
+    ///
+    /// A `RotatingFileHandler`, or a [`RotatingError`] naming the path and step that failed.
+    pub fn with_vfs(
+        vfs: impl Vfs + 'static,
+        base_path: &str,
+        max_bytes: Option<u64>,
         backup_count: usize,
         header: Option<Vec<u8>>,
-    ) -> io::Result<Self> {
-        let mut file = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .open(base_path)?;
+    ) -> Result<Self, RotatingError> {
         if let Some(ref header) = header {
-            if header.len() as u64 > max_bytes {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "Header size exceeds maximum file size",
+            if max_bytes.is_some_and(|max_bytes| header.len() as u64 > max_bytes) {
+                return Err(RotatingError::new(
+                    "write header",
+                    base_path,
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "Header size exceeds maximum file size",
+                    ),
                 ));
             }
-            file.write_all(header)?;
         }
-        let current_size = file.metadata()?.len();
-        Ok(Self {
+        let file = vfs
+            .open_append(base_path)
+            .with_context(|| IoErrorContext::new("open log file", base_path))?;
+        // Read the length of the file exactly as found on disk, before writing anything to it,
+        // so an already-oversized file is rotated on its own content rather than on content plus
+        // a header we are about to append to it.
+        let current_size = vfs
+            .len(base_path)
+            .with_context(|| IoErrorContext::new("read log file length", base_path))?;
+        let mut handler = Self {
             base_path: base_path.to_string(),
             max_bytes,
             backup_count,
             current_size,
             file,
             header,
-        })
+            vfs: Box::new(vfs),
+        };
+        if handler.needs_rotation(0) {
+            handler.rotate()?; // Rotates the file as found and writes the header (if any) to the fresh file.
+        } else if let Some(ref header) = handler.header {
+            handler
+                .file
+                .write_all(header)
+                .with_context(|| IoErrorContext::new("write header", &handler.base_path))?;
+            handler.current_size += header.len() as u64;
+        }
+        Ok(handler)
+    }
+
+    /// Returns `true` if writing `additional` more bytes would push the log file over
+    /// `max_bytes`, or if it is already over `max_bytes` (e.g. a pre-existing file found at
+    /// construction time). Always `false` when rotation is disabled.
+    fn needs_rotation(&self, additional: u64) -> bool {
+        self.max_bytes
+            .is_some_and(|max_bytes| self.current_size + additional > max_bytes)
     }
 
     /// Rotates the log files.
     ///
     /// This method renames the current log file and creates a new one.
     /// It keeps a specified number of backup files.
-    fn rotate(&mut self) -> io::Result<()> {
-        self.file.flush()?; // Ensure all data is written to the file before rotating.
+    fn rotate(&mut self) -> Result<(), RotatingError> {
+        self.file
+            .flush()
+            .with_context(|| IoErrorContext::new("flush log file", &self.base_path))?; // Ensure all data is written to the file before rotating.
         for i in (1..self.backup_count).rev() {
             let src = format!("{}.{}", self.base_path, i - 1);
             let dst = format!("{}.{}", self.base_path, i);
-            if Path::new(&src).exists() {
-                std::fs::rename(src, dst)?; // Rename the backup files.
+            if self.vfs.exists(&src) {
+                self.vfs
+                    .rename(&src, &dst)
+                    .with_context(|| IoErrorContext::new("rename backup", &src))?; // Rename the backup files.
             }
         }
-        std::fs::rename(&self.base_path, format!("{}.0", self.base_path))?; // Rename the current log file.
-        self.file = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .open(&self.base_path)?; // Create a new log file.
+        let first_backup = format!("{}.0", self.base_path);
+        self.vfs
+            .rename(&self.base_path, &first_backup)
+            .with_context(|| IoErrorContext::new("rename current log file", &self.base_path))?; // Rename the current log file.
+        self.file = self
+            .vfs
+            .open_append(&self.base_path)
+            .with_context(|| IoErrorContext::new("open log file", &self.base_path))?; // Create a new log file.
         if let Some(ref header) = self.header {
-            self.file.write_all(header)?; // Write the header to the new log file.
+            self.file
+                .write_all(header)
+                .with_context(|| IoErrorContext::new("write header", &self.base_path))?; // Write the header to the new log file.
         }
         self.current_size = 0; // Reset the current size.
         Ok(())
@@ -114,8 +190,10 @@ impl RotatingFileHandler {
 
     /// Writes bytes to the log file.
     ///
-    /// This method writes the provided bytes to the log file. If the file size
-    /// exceeds the maximum size, it rotates the log files.
+    /// This method writes the provided bytes to the log file. If the file is already over
+    /// `max_bytes`, or would be pushed over it by this write, it rotates the log files first.
+    /// Rotation never splits a record: a single `bytes` larger than `max_bytes` is still written
+    /// whole, after at most one rotation.
     ///
     /// # Arguments
     ///
@@ -123,12 +201,14 @@ impl RotatingFileHandler {
     ///
     /// # Returns
     ///
-    /// An `io::Result` indicating success or failure.
-    pub fn emit(&mut self, bytes: &[u8]) -> io::Result<()> {
-        if self.current_size + bytes.len() as u64 > self.max_bytes {
+    /// `Ok(())`, or a [`RotatingError`] naming the path and step that failed.
+    pub fn emit(&mut self, bytes: &[u8]) -> Result<(), RotatingError> {
+        if self.needs_rotation(bytes.len() as u64) {
             self.rotate()?; // Rotate the log files if the size exceeds the maximum.
         }
-        self.file.write_all(bytes)?; // Write the bytes to the log file.
+        self.file
+            .write_all(bytes)
+            .with_context(|| IoErrorContext::new("write record", &self.base_path))?; // Write the bytes to the log file.
         self.current_size += bytes.len() as u64; // Update the current size.
         Ok(())
     }
@@ -168,91 +248,373 @@ impl Write for RotatingFileHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::error::Error as StdError;
     use std::fs;
     use std::io::Write;
+    use std::path::Path;
+
+    /// The single operation a [`FailingVfs`] forces to fail, so individual error-context call
+    /// sites in `RotatingFileHandler` can be exercised in isolation.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Failure {
+        Open,
+        Write,
+        Rename,
+        Len,
+    }
+
+    /// A [`Vfs`] that wraps a [`MemoryVfs`] but forces one operation to fail, so call sites that
+    /// attach [`IoErrorContext`] can be tested without relying on real filesystem faults.
+    #[derive(Clone)]
+    struct FailingVfs {
+        inner: MemoryVfs,
+        fail: Failure,
+    }
+
+    /// A writer that always fails, used to force a write (as opposed to open) failure.
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "forced write failure",
+            ))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Vfs for FailingVfs {
+        fn open_append(&self, path: &str) -> io::Result<Box<dyn Write + Send>> {
+            if self.fail == Failure::Open {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "forced open failure",
+                ));
+            }
+            let file = self.inner.open_append(path)?;
+            if self.fail == Failure::Write {
+                return Ok(Box::new(FailingWriter));
+            }
+            Ok(file)
+        }
+
+        fn rename(&self, src: &str, dst: &str) -> io::Result<()> {
+            if self.fail == Failure::Rename {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "forced rename failure",
+                ));
+            }
+            self.inner.rename(src, dst)
+        }
+
+        fn exists(&self, path: &str) -> bool {
+            self.inner.exists(path)
+        }
+
+        fn len(&self, path: &str) -> io::Result<u64> {
+            if self.fail == Failure::Len {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "forced len failure",
+                ));
+            }
+            self.inner.len(path)
+        }
+    }
 
     /// Test that the log file rotates when the maximum file size is reached.
     #[test]
     fn test_rotation_on_max_file_size() {
-        let mut handler = RotatingFileHandler::new("test_case_1_log.txt", 10, 3, None).unwrap();
+        let vfs = MemoryVfs::new();
+        let mut handler =
+            RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", Some(10), 3, None).unwrap();
 
         // Emit data to reach the maximum file size but not exceed it.
         handler.emit(b"12345").unwrap();
         handler.emit(b"67890").unwrap();
-        assert!(Path::new("test_case_1_log.txt").exists());
-        assert!(!Path::new("test_case_1_log.txt.0").exists());
+        assert!(vfs.exists("log.txt"));
+        assert!(!vfs.exists("log.txt.0"));
 
         // Emit more data to exceed the maximum file size and trigger rotation.
         handler.emit(b"abcde").unwrap();
         handler.emit(b"fghij").unwrap();
-        assert!(Path::new("test_case_1_log.txt").exists());
-        assert!(Path::new("test_case_1_log.txt.0").exists());
-        assert!(!Path::new("test_case_1_log.txt.1").exists());
-
-        let content = fs::read_to_string("test_case_1_log.txt").unwrap();
-        assert_eq!(content, "abcdefghij");
-
-        let content = fs::read_to_string("test_case_1_log.txt.0").unwrap();
-        assert_eq!(content, "1234567890");
+        assert!(vfs.exists("log.txt"));
+        assert!(vfs.exists("log.txt.0"));
+        assert!(!vfs.exists("log.txt.1"));
 
-        let _ = fs::remove_file("test_case_1_log.txt");
-        for i in 0..1 {
-            let _ = fs::remove_file(format!("test_case_1_log.txt.{}", i));
-        }
+        assert_eq!(vfs.contents("log.txt"), b"abcdefghij");
+        assert_eq!(vfs.contents("log.txt.0"), b"1234567890");
     }
 
     /// Test that the log file rotates when the maximum backup count is reached.
     #[test]
     fn test_rotation_on_max_count() {
-        let mut handler = RotatingFileHandler::new("test_case_2_log.txt", 10, 2, None).unwrap();
+        let vfs = MemoryVfs::new();
+        let mut handler =
+            RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", Some(10), 2, None).unwrap();
         handler.emit(b"1234567890").unwrap();
         handler.emit(b"abcdefghij").unwrap(); // This should trigger a rotation.
         handler.emit(b"klmnopqrst").unwrap(); // This should trigger a rotation.
         handler.emit(b"uvwxyzabcd").unwrap(); // This should trigger a rotation.
 
-        assert!(Path::new("test_case_2_log.txt").exists());
-        assert!(Path::new("test_case_2_log.txt.0").exists());
-        assert!(Path::new("test_case_2_log.txt.1").exists());
-        assert!(!Path::new("test_case_2_log.txt.2").exists()); // Max 2 backups should exist.
-
-        let content = fs::read_to_string("test_case_2_log.txt").unwrap();
-        assert_eq!(content, "uvwxyzabcd");
+        assert!(vfs.exists("log.txt"));
+        assert!(vfs.exists("log.txt.0"));
+        assert!(vfs.exists("log.txt.1"));
+        assert!(!vfs.exists("log.txt.2")); // Max 2 backups should exist.
 
-        let content = fs::read_to_string("test_case_2_log.txt.0").unwrap();
-        assert_eq!(content, "klmnopqrst");
-
-        let content = fs::read_to_string("test_case_2_log.txt.1").unwrap();
-        assert_eq!(content, "abcdefghij");
-
-        let _ = fs::remove_file("test_case_2_log.txt");
-        for i in 0..2 {
-            let _ = fs::remove_file(format!("test_case_2_log.txt.{}", i));
-        }
+        assert_eq!(vfs.contents("log.txt"), b"uvwxyzabcd");
+        assert_eq!(vfs.contents("log.txt.0"), b"klmnopqrst");
+        assert_eq!(vfs.contents("log.txt.1"), b"abcdefghij");
     }
 
     /// Test that the `emit` method writes data to the log file.
     #[test]
     fn test_emit() {
-        let mut handler = RotatingFileHandler::new("test_case_3_log.txt", 50, 1, None).unwrap();
+        let vfs = MemoryVfs::new();
+        let mut handler =
+            RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", Some(50), 1, None).unwrap();
         handler.emit(b"Hello, world!").unwrap();
         handler.emit(b" More data.").unwrap();
 
-        let content = fs::read_to_string("test_case_3_log.txt").unwrap();
-        assert_eq!(content, "Hello, world! More data.");
-
-        let _ = fs::remove_file("test_case_3_log.txt");
+        assert_eq!(vfs.contents("log.txt"), b"Hello, world! More data.");
     }
 
     /// Test that the `write` method writes data to the log file.
     #[test]
     fn test_write_trait() {
-        let mut handler = RotatingFileHandler::new("test_case_4_log.txt", 50, 1, None).unwrap();
+        let vfs = MemoryVfs::new();
+        let mut handler =
+            RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", Some(50), 1, None).unwrap();
         write!(handler, "Hello, world!").unwrap();
         write!(handler, " More data.").unwrap();
 
-        let content = fs::read_to_string("test_case_4_log.txt").unwrap();
-        assert_eq!(content, "Hello, world! More data.");
+        assert_eq!(vfs.contents("log.txt"), b"Hello, world! More data.");
+    }
+
+    /// Test that `max_bytes: None` disables rotation and the handler just keeps appending.
+    #[test]
+    fn test_no_rotation_when_max_bytes_is_none() {
+        let vfs = MemoryVfs::new();
+        let mut handler = RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", None, 2, None).unwrap();
+        for _ in 0..10 {
+            handler.emit(b"1234567890").unwrap();
+        }
+        assert!(!vfs.exists("log.txt.0"));
+        assert_eq!(vfs.contents("log.txt").len(), 100);
+    }
+
+    /// Test that a pre-existing file already larger than `max_bytes` is rotated once at
+    /// construction time, before any new data is written to it.
+    #[test]
+    fn test_rotates_already_oversized_file_on_open() {
+        let vfs = MemoryVfs::new();
+        {
+            let mut seed =
+                RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", None, 2, None).unwrap();
+            seed.emit(b"1234567890").unwrap();
+        }
+        assert_eq!(vfs.contents("log.txt").len(), 10);
+
+        let mut handler =
+            RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", Some(5), 2, None).unwrap();
+        assert!(vfs.exists("log.txt.0"));
+        assert_eq!(vfs.contents("log.txt.0"), b"1234567890");
+        assert_eq!(vfs.contents("log.txt"), b"");
+
+        // A record larger than max_bytes is still written whole, after at most one rotation.
+        handler.emit(b"abcdefghij").unwrap();
+        assert_eq!(vfs.contents("log.txt"), b"abcdefghij");
+    }
+
+    /// Test that rotating an already-oversized file on open does not pollute the backup with a
+    /// header, and that the header ends up only in the fresh active file.
+    #[test]
+    fn test_rotates_already_oversized_file_on_open_with_header() {
+        let vfs = MemoryVfs::new();
+        {
+            let mut seed =
+                RotatingFileHandler::with_vfs(vfs.clone(), "log.txt", None, 2, None).unwrap();
+            seed.emit(b"1234567890").unwrap();
+        }
+        assert_eq!(vfs.contents("log.txt").len(), 10);
+
+        RotatingFileHandler::with_vfs(
+            vfs.clone(),
+            "log.txt",
+            Some(5),
+            2,
+            Some(b"HDR".to_vec()),
+        )
+        .unwrap();
+
+        assert!(vfs.exists("log.txt.0"));
+        assert_eq!(vfs.contents("log.txt.0"), b"1234567890");
+        assert_eq!(vfs.contents("log.txt"), b"HDR");
+    }
+
+    /// Unwraps the error of a `RotatingFileHandler` construction result. `RotatingFileHandler`
+    /// does not implement `Debug`, so `Result::unwrap_err` is not available.
+    fn expect_construction_err(result: Result<RotatingFileHandler, RotatingError>) -> RotatingError {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        }
+    }
+
+    /// Test that a failure opening the log file is reported with the "open log file" operation
+    /// and the offending path, and that the original `io::Error` is reachable via `source()`.
+    #[test]
+    fn test_open_append_error_has_path_and_operation_context() {
+        let vfs = FailingVfs {
+            inner: MemoryVfs::new(),
+            fail: Failure::Open,
+        };
+        let err = expect_construction_err(RotatingFileHandler::with_vfs(
+            vfs, "log.txt", Some(10), 2, None,
+        ));
+
+        let message = err.to_string();
+        assert!(message.contains("open log file"));
+        assert!(message.contains("log.txt"));
+
+        let source = err.source().expect("RotatingError must carry the io::Error");
+        assert_eq!(source.to_string(), "forced open failure");
+    }
+
+    /// Test that a failure reading the existing file's length is reported with the
+    /// "read log file length" operation and the offending path.
+    #[test]
+    fn test_len_error_has_path_and_operation_context() {
+        let vfs = FailingVfs {
+            inner: MemoryVfs::new(),
+            fail: Failure::Len,
+        };
+        let err = expect_construction_err(RotatingFileHandler::with_vfs(
+            vfs, "log.txt", Some(10), 2, None,
+        ));
+
+        let message = err.to_string();
+        assert!(message.contains("read log file length"));
+        assert!(message.contains("log.txt"));
+    }
+
+    /// Test that a failure writing the header on construction is reported with the
+    /// "write header" operation and the offending path.
+    #[test]
+    fn test_write_header_error_has_path_and_operation_context() {
+        let vfs = FailingVfs {
+            inner: MemoryVfs::new(),
+            fail: Failure::Write,
+        };
+        let err = expect_construction_err(RotatingFileHandler::with_vfs(
+            vfs,
+            "log.txt",
+            Some(100),
+            2,
+            Some(b"HDR".to_vec()),
+        ));
+
+        let message = err.to_string();
+        assert!(message.contains("write header"));
+        assert!(message.contains("log.txt"));
+    }
+
+    /// Test that a failure renaming an existing backup file during rotation is reported with the
+    /// "rename backup" operation and the backup's own path, not the base path.
+    #[test]
+    fn test_rename_backup_error_has_path_and_operation_context() {
+        let inner = MemoryVfs::new();
+        {
+            let mut backup = inner.open_append("log.txt.0").unwrap();
+            backup.write_all(b"old").unwrap();
+        }
+        let vfs = FailingVfs {
+            inner,
+            fail: Failure::Rename,
+        };
+        let mut handler = RotatingFileHandler::with_vfs(vfs, "log.txt", Some(1), 2, None).unwrap();
+        let err = handler.emit(b"12345").unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("rename backup"));
+        assert!(message.contains("log.txt.0"));
+    }
+
+    /// Test that a failure renaming the current log file into its first backup slot is reported
+    /// with the "rename current log file" operation and the base path.
+    #[test]
+    fn test_rename_current_log_file_error_has_path_and_operation_context() {
+        let vfs = FailingVfs {
+            inner: MemoryVfs::new(),
+            fail: Failure::Rename,
+        };
+        let mut handler = RotatingFileHandler::with_vfs(vfs, "log.txt", Some(1), 1, None).unwrap();
+        let err = handler.emit(b"12345").unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("rename current log file"));
+        assert!(message.contains("log.txt"));
+    }
+
+    /// Test that a failure writing a record is reported with the "write record" operation and
+    /// the offending path, and that it round-trips correctly through the `Write` impl: `?` in
+    /// `write()` must convert `RotatingError` into an `io::Error` that still carries the same
+    /// context.
+    #[test]
+    fn test_write_record_error_round_trips_through_write_trait() {
+        let vfs = FailingVfs {
+            inner: MemoryVfs::new(),
+            fail: Failure::Write,
+        };
+        let mut handler = RotatingFileHandler::with_vfs(vfs, "log.txt", None, 2, None).unwrap();
+        let err = write!(handler, "data").unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        let message = err.to_string();
+        assert!(message.contains("write record"));
+        assert!(message.contains("log.txt"));
+    }
+
+    /// Test that `RotatingFileHandler` is `Send`, so it can still be handed to another thread or
+    /// wrapped in a shared `Mutex`, as a `std::fs::File`-backed handler always could.
+    #[test]
+    fn test_rotating_file_handler_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<RotatingFileHandler>();
+    }
+
+    /// Test that the `DiskVfs` backend (used by `RotatingFileHandler::new`) drives a full
+    /// rename-chain rotation on the real filesystem, not just its in-memory mirror.
+    #[test]
+    fn test_disk_vfs_rotation_on_max_file_size() {
+        let mut handler =
+            RotatingFileHandler::new("test_case_disk_vfs_log.txt", Some(10), 3, None).unwrap();
+
+        // Emit data to reach the maximum file size but not exceed it.
+        handler.emit(b"12345").unwrap();
+        handler.emit(b"67890").unwrap();
+        assert!(Path::new("test_case_disk_vfs_log.txt").exists());
+        assert!(!Path::new("test_case_disk_vfs_log.txt.0").exists());
+
+        // Emit more data to exceed the maximum file size and trigger rotation.
+        handler.emit(b"abcde").unwrap();
+        handler.emit(b"fghij").unwrap();
+        assert!(Path::new("test_case_disk_vfs_log.txt").exists());
+        assert!(Path::new("test_case_disk_vfs_log.txt.0").exists());
+        assert!(!Path::new("test_case_disk_vfs_log.txt.1").exists());
+
+        let content = fs::read_to_string("test_case_disk_vfs_log.txt").unwrap();
+        assert_eq!(content, "abcdefghij");
+
+        let content = fs::read_to_string("test_case_disk_vfs_log.txt.0").unwrap();
+        assert_eq!(content, "1234567890");
 
-        let _ = fs::remove_file("test_case_4_log.txt");
+        let _ = fs::remove_file("test_case_disk_vfs_log.txt");
+        let _ = fs::remove_file("test_case_disk_vfs_log.txt.0");
     }
 }
@@ -0,0 +1,143 @@
+//! Filesystem abstraction used by [`crate::RotatingFileHandler`].
+//!
+//! Every operation the handler needs (opening a file for append, renaming, checking existence,
+//! and reading a length) is expressed through the [`Vfs`] trait instead of being hardwired to
+//! `std::fs`. This mirrors the `Vfs` used by Mercurial's `LogFile`: a backend resolves a `name`
+//! to wherever it actually lives (disk, memory, a sandboxed base directory, ...) and performs all
+//! I/O on the caller's behalf.
+
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// A pluggable filesystem backend for [`crate::RotatingFileHandler`].
+///
+/// Implement this trait to root the handler at a base directory, sandbox it in memory, or
+/// otherwise redirect its I/O away from the real filesystem. Requires `Send` so a
+/// `RotatingFileHandler` can still be handed to another thread or wrapped in a shared `Mutex`,
+/// as a `std::fs::File`-backed handler always could.
+pub trait Vfs: Send {
+    /// Opens `path` for appending, creating it if it does not already exist.
+    fn open_append(&self, path: &str) -> io::Result<Box<dyn Write + Send>>;
+
+    /// Renames `src` to `dst`, overwriting `dst` if it already exists.
+    fn rename(&self, src: &str, dst: &str) -> io::Result<()>;
+
+    /// Returns `true` if `path` exists.
+    fn exists(&self, path: &str) -> bool;
+
+    /// Returns the length, in bytes, of the file at `path`.
+    fn len(&self, path: &str) -> io::Result<u64>;
+}
+
+/// The default [`Vfs`], backed by the real filesystem via `std::fs`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DiskVfs;
+
+impl Vfs for DiskVfs {
+    fn open_append(&self, path: &str) -> io::Result<Box<dyn Write + Send>> {
+        let file = OpenOptions::new().append(true).create(true).open(path)?;
+        Ok(Box::new(file))
+    }
+
+    fn rename(&self, src: &str, dst: &str) -> io::Result<()> {
+        std::fs::rename(src, dst)
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        Path::new(path).exists()
+    }
+
+    fn len(&self, path: &str) -> io::Result<u64> {
+        std::fs::metadata(path).map(|metadata| metadata.len())
+    }
+}
+
+/// An in-memory [`Vfs`] backed by a shared map of path to bytes.
+///
+/// Useful for exercising rotation logic in tests without touching the real filesystem, or for
+/// embedding a `RotatingFileHandler` over a sandboxed, in-process store. Cloning a `MemoryVfs`
+/// shares the same underlying storage, so a clone can be kept aside to inspect what a handler
+/// has written. Backed by `Arc<Mutex<...>>` rather than `Rc<RefCell<...>>` so it satisfies
+/// [`Vfs`]'s `Send` bound.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryVfs {
+    files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MemoryVfs {
+    /// Creates a new, empty `MemoryVfs`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the bytes currently stored at `path`, or an empty `Vec` if it does not
+    /// exist.
+    pub fn contents(&self, path: &str) -> Vec<u8> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(path)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+struct MemoryFile {
+    files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    path: String,
+}
+
+impl Write for MemoryFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.files
+            .lock()
+            .unwrap()
+            .entry(self.path.clone())
+            .or_default()
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Vfs for MemoryVfs {
+    fn open_append(&self, path: &str) -> io::Result<Box<dyn Write + Send>> {
+        self.files
+            .lock()
+            .unwrap()
+            .entry(path.to_string())
+            .or_default();
+        Ok(Box::new(MemoryFile {
+            files: Arc::clone(&self.files),
+            path: path.to_string(),
+        }))
+    }
+
+    fn rename(&self, src: &str, dst: &str) -> io::Result<()> {
+        let mut files = self.files.lock().unwrap();
+        let data = files.remove(src).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", src))
+        })?;
+        files.insert(dst.to_string(), data);
+        Ok(())
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.lock().unwrap().contains_key(path)
+    }
+
+    fn len(&self, path: &str) -> io::Result<u64> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|data| data.len() as u64)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", path)))
+    }
+}